@@ -0,0 +1,54 @@
+//! Module for [CommandLineTagBuilder].
+
+use crate::TagType;
+use alloc::vec::Vec;
+
+/// Builder for the bytes of a [`crate::CommandLineTag`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use multiboot2::builder::CommandLineTagBuilder;
+/// let bytes = CommandLineTagBuilder::new("console=ttyS0").build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct CommandLineTagBuilder<'a> {
+    cmdline: &'a str,
+}
+
+impl<'a> CommandLineTagBuilder<'a> {
+    /// Creates a new builder for the given command line string.
+    pub fn new(cmdline: &'a str) -> Self {
+        Self { cmdline }
+    }
+
+    /// Builds the tag bytes: the 8-byte tag header followed by the
+    /// NUL-terminated command line string.
+    pub fn build(self) -> Vec<u8> {
+        let size = 4 + 4 + self.cmdline.len() + 1;
+
+        let mut bytes = Vec::with_capacity(size);
+        bytes.extend(TagType::Cmdline.val().to_ne_bytes());
+        bytes.extend((size as u32).to_ne_bytes());
+        bytes.extend(self.cmdline.as_bytes());
+        bytes.push(0);
+
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CommandLineTag, Tag};
+
+    /// Tests that a tag built by [`CommandLineTagBuilder`] round-trips
+    /// through the parsing side of the crate.
+    #[test]
+    fn test_build_roundtrip() {
+        let bytes = CommandLineTagBuilder::new("/bootarg").build();
+        let tag = unsafe { &*bytes.as_ptr().cast::<Tag>() };
+        let tag = tag.cast_tag::<CommandLineTag>();
+        assert_eq!(tag.command_line().expect("must be valid UTF-8"), "/bootarg");
+    }
+}
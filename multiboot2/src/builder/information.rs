@@ -0,0 +1,81 @@
+//! Module for [BootInformationBuilder].
+
+use super::align_up;
+use crate::TagType;
+use alloc::vec::Vec;
+
+/// End tag size, per the Multiboot2 spec: just the 8-byte tag header.
+const END_TAG_SIZE: usize = 8;
+
+/// Builds the bytes of a full Multiboot2 boot information structure out of
+/// already-built tags (e.g. from [`super::CommandLineTagBuilder`]).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use multiboot2::builder::{BootInformationBuilder, CommandLineTagBuilder};
+/// let mbi = BootInformationBuilder::new()
+///     .add_tag(CommandLineTagBuilder::new("console=ttyS0").build())
+///     .build();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct BootInformationBuilder {
+    tags: Vec<Vec<u8>>,
+}
+
+impl BootInformationBuilder {
+    /// Creates a new, empty builder.
+    pub fn new() -> Self {
+        Self { tags: Vec::new() }
+    }
+
+    /// Appends the bytes of an already-built tag.
+    pub fn add_tag(mut self, tag_bytes: Vec<u8>) -> Self {
+        self.tags.push(tag_bytes);
+        self
+    }
+
+    /// Concatenates every added tag, padding each to 8-byte alignment,
+    /// appends the end tag, and prepends the MBI header with the correct
+    /// total size.
+    pub fn build(self) -> Vec<u8> {
+        let mut body = Vec::new();
+        for tag in &self.tags {
+            body.extend(tag);
+            body.resize(align_up(body.len()), 0);
+        }
+        body.extend(TagType::End.val().to_ne_bytes());
+        body.extend((END_TAG_SIZE as u32).to_ne_bytes());
+
+        let total_size = (8 + body.len()) as u32;
+
+        let mut bytes = Vec::with_capacity(total_size as usize);
+        bytes.extend(total_size.to_ne_bytes());
+        bytes.extend(0u32.to_ne_bytes()); // reserved
+        bytes.extend(body);
+
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::CommandLineTagBuilder;
+
+    /// Tests that the assembled MBI's total size covers the header, every
+    /// tag (8-byte aligned), and the end tag.
+    #[test]
+    fn test_build_total_size() {
+        let cmdline = CommandLineTagBuilder::new("a").build();
+        let cmdline_len = cmdline.len();
+
+        let mbi = BootInformationBuilder::new().add_tag(cmdline).build();
+
+        let expected_size = 8 + align_up(cmdline_len) + END_TAG_SIZE;
+        assert_eq!(mbi.len(), expected_size);
+
+        let total_size = u32::from_ne_bytes(mbi[0..4].try_into().unwrap());
+        assert_eq!(total_size as usize, expected_size);
+    }
+}
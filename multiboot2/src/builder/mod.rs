@@ -0,0 +1,23 @@
+//! Module for the tag and boot information builders.
+//!
+//! The parsing side of this crate only ever borrows an existing Multiboot2
+//! structure; these builders are the symmetric counterpart for code that
+//! needs to *produce* one, e.g. a bootloader or an emulator, or a test that
+//! wants a single source of truth for tag bytes instead of ad-hoc byte
+//! arrays.
+
+mod command_line;
+mod framebuffer;
+mod information;
+mod smbios;
+
+pub use command_line::CommandLineTagBuilder;
+pub use framebuffer::FramebufferTagBuilder;
+pub use information::BootInformationBuilder;
+pub use smbios::SmbiosTagBuilder;
+
+/// Rounds `size` up to the next multiple of 8, the alignment every
+/// Multiboot2 tag must start at.
+pub(crate) const fn align_up(size: usize) -> usize {
+    (size + 7) & !7
+}
@@ -1,5 +1,6 @@
 //! Module for [CommandLineTag].
 
+use crate::tag_error::TagError;
 use crate::{Tag, TagTrait, TagTypeId};
 use core::fmt::{Debug, Formatter};
 use core::str;
@@ -57,6 +58,17 @@ impl TagTrait for CommandLineTag {
         assert!(base_tag.size >= 8);
         base_tag.size as usize - tag_base_size
     }
+
+    fn try_dst_size(base_tag: &Tag) -> Result<usize, TagError> {
+        let tag_base_size = 8;
+        if base_tag.size < 8 {
+            return Err(TagError::TooSmall {
+                size: base_tag.size,
+                minimum: tag_base_size,
+            });
+        }
+        Ok(base_tag.size as usize - tag_base_size)
+    }
 }
 
 #[cfg(test)]
@@ -91,4 +103,30 @@ mod tests {
         assert_eq!({ tag.typ }, TagType::Cmdline);
         assert_eq!(tag.command_line().expect("must be valid UTF-8"), MSG);
     }
+
+    /// Tests that a tag claiming a size smaller than its own header is
+    /// rejected instead of underflowing.
+    #[test]
+    fn test_try_dst_size_too_small() {
+        use crate::tag_error::TagError;
+        use crate::TagTrait;
+
+        // A tag header claiming a size of 4, smaller than the 8-byte header
+        // it must at least contain.
+        let bytes: std::vec::Vec<u8> =
+            [&(TagType::Cmdline.val()).to_ne_bytes(), &4u32.to_ne_bytes()]
+                .iter()
+                .flat_map(|b| b.iter())
+                .copied()
+                .collect();
+        let tag = unsafe { &*bytes.as_ptr().cast::<Tag>() };
+
+        assert_eq!(
+            CommandLineTag::try_dst_size(tag),
+            Err(TagError::TooSmall {
+                size: 4,
+                minimum: 8
+            })
+        );
+    }
 }
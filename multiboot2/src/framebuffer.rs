@@ -1,3 +1,4 @@
+use crate::tag_error::{self, TagError};
 use crate::tag_type::Tag;
 use crate::Reader;
 use core::slice;
@@ -67,6 +68,30 @@ pub enum FramebufferType<'a> {
     Text,
 }
 
+impl<'a> FramebufferType<'a> {
+    /// Finds the palette index whose color is closest (by squared distance
+    /// in RGB space) to the given color.
+    ///
+    /// Returns `None` if [`Self`] is not [`FramebufferType::Indexed`] or the
+    /// palette is empty.
+    pub fn nearest_palette_index(&self, r: u8, g: u8, b: u8) -> Option<u8> {
+        let FramebufferType::Indexed { palette } = self else {
+            return None;
+        };
+
+        palette
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, color)| {
+                let dr = i32::from(color.red) - i32::from(r);
+                let dg = i32::from(color.green) - i32::from(g);
+                let db = i32::from(color.blue) - i32::from(b);
+                dr * dr + dg * dg + db * db
+            })
+            .map(|(index, _)| index as u8)
+    }
+}
+
 /// An RGB color type field.
 #[derive(Debug, PartialEq, Eq)]
 pub struct FramebufferField {
@@ -77,6 +102,19 @@ pub struct FramebufferField {
     pub size: u8,
 }
 
+impl FramebufferField {
+    /// Scales an 8-bit color component down to [`Self::size`] bits and
+    /// shifts it into place at [`Self::position`].
+    ///
+    /// [`Self::position`] is masked to `0..32` first, since it is taken
+    /// verbatim from the tag's untrusted RGB field bytes and would
+    /// otherwise overflow the shift for a corrupt tag.
+    fn pack(&self, component: u8) -> u32 {
+        let scaled = u32::from(component) >> (8 - self.size.min(8));
+        scaled << (self.position & 31)
+    }
+}
+
 /// A framebuffer color descriptor in the palette.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(C, packed)] // only repr(C) would add unwanted padding at the end
@@ -99,6 +137,210 @@ pub struct UnknownFramebufferType(u8);
 #[cfg(feature = "unstable")]
 impl core::error::Error for UnknownFramebufferType {}
 
+/// Error when a pixel coordinate lies outside the framebuffer bounds.
+#[derive(Debug, Copy, Clone, Display, PartialEq, Eq)]
+#[display(fmt = "Pixel coordinate ({}, {}) is out of bounds", _0, _1)]
+pub struct PixelOutOfBounds(u32, u32);
+
+#[cfg(feature = "unstable")]
+impl core::error::Error for PixelOutOfBounds {}
+
+/// Error when [`FramebufferTag::put_pixel`] cannot write a pixel.
+#[derive(Debug, Copy, Clone, Display, PartialEq, Eq)]
+pub enum PutPixelError {
+    /// See [`PixelOutOfBounds`].
+    #[display(fmt = "{}", _0)]
+    OutOfBounds(PixelOutOfBounds),
+    /// The tag's `bpp` implies a pixel wider than the 4 bytes
+    /// [`FramebufferTag::put_pixel`] supports.
+    #[display(fmt = "Unsupported bits per pixel: {}", _0)]
+    UnsupportedBpp(u8),
+}
+
+#[cfg(feature = "unstable")]
+impl core::error::Error for PutPixelError {}
+
+impl From<PixelOutOfBounds> for PutPixelError {
+    fn from(err: PixelOutOfBounds) -> Self {
+        Self::OutOfBounds(err)
+    }
+}
+
+impl<'a> FramebufferTag<'a> {
+    /// Packs the given 8-bit RGB components into a single pixel value laid
+    /// out according to this framebuffer's [`FramebufferType::RGB`] field
+    /// positions and widths.
+    ///
+    /// Returns `None` if [`Self::buffer_type`] is not [`FramebufferType::RGB`].
+    pub fn pack_color(&self, r: u8, g: u8, b: u8) -> Option<u32> {
+        match &self.buffer_type {
+            FramebufferType::RGB { red, green, blue } => {
+                Some(red.pack(r) | green.pack(g) | blue.pack(b))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the raw framebuffer memory as a mutable byte slice, computing
+    /// its length as `pitch * height`.
+    ///
+    /// # Safety
+    /// The caller must ensure that [`Self::address`] points to valid,
+    /// writable memory of at least `pitch * height` bytes, and that no other
+    /// reference to this memory exists for the lifetime of the returned
+    /// slice.
+    #[allow(clippy::mut_from_ref)]
+    pub unsafe fn framebuffer_slice(&self) -> &mut [u8] {
+        let len = self.pitch as usize * self.height as usize;
+        slice::from_raw_parts_mut(self.address as *mut u8, len)
+    }
+
+    /// Writes `value` (the low `bpp / 8` bytes, native endian) to the pixel
+    /// at `(x, y)`.
+    ///
+    /// Returns [`PixelOutOfBounds`] if `x >= width` or `y >= height`, or
+    /// [`PutPixelError::UnsupportedBpp`] if [`Self::bpp`] implies a pixel
+    /// wider than `value`, instead of writing out of bounds.
+    ///
+    /// # Safety
+    /// Same requirements as [`Self::framebuffer_slice`].
+    pub unsafe fn put_pixel(&self, x: u32, y: u32, value: u32) -> Result<(), PutPixelError> {
+        if x >= self.width || y >= self.height {
+            return Err(PixelOutOfBounds(x, y).into());
+        }
+
+        let bytes_per_pixel = self.bpp as usize / 8;
+        if bytes_per_pixel > core::mem::size_of::<u32>() {
+            return Err(PutPixelError::UnsupportedBpp(self.bpp));
+        }
+
+        let offset = y as usize * self.pitch as usize + x as usize * bytes_per_pixel;
+        let value = value.to_ne_bytes();
+        let dst = &mut self.framebuffer_slice()[offset..offset + bytes_per_pixel];
+        dst.copy_from_slice(&value[..bytes_per_pixel]);
+
+        Ok(())
+    }
+}
+
+/// Error when parsing a [`Tag`] into a [`FramebufferTag`] via
+/// [`checked_framebuffer_tag`].
+#[derive(Debug, Copy, Clone, Display, PartialEq, Eq)]
+pub enum FramebufferTagError {
+    /// See [`UnknownFramebufferType`].
+    #[display(fmt = "{}", _0)]
+    UnknownType(UnknownFramebufferType),
+    /// See [`TagError`].
+    #[display(fmt = "{}", _0)]
+    TagError(TagError),
+}
+
+#[cfg(feature = "unstable")]
+impl core::error::Error for FramebufferTagError {}
+
+impl From<UnknownFramebufferType> for FramebufferTagError {
+    fn from(err: UnknownFramebufferType) -> Self {
+        Self::UnknownType(err)
+    }
+}
+
+impl From<TagError> for FramebufferTagError {
+    fn from(err: TagError) -> Self {
+        Self::TagError(err)
+    }
+}
+
+/// Size, in bytes, of the fixed header every framebuffer tag has: the
+/// 8-byte tag header, `address`, `pitch`, `width`, `height`, `bpp`,
+/// `type_no` and the 2 skipped/reserved bytes. This is also the offset, in
+/// bytes from the start of the tag, of the `num_colors` field in an
+/// [`FramebufferTypeId::Indexed`] framebuffer tag.
+const FRAMEBUFFER_HEADER_SIZE: usize = 32;
+
+/// Size, in bytes, of the `num_colors` field following
+/// [`FRAMEBUFFER_HEADER_SIZE`] in an [`FramebufferTypeId::Indexed`] tag.
+const INDEXED_NUM_COLORS_SIZE: usize = 4;
+
+/// Size, in bytes, of the red/green/blue position+mask fields following
+/// [`FRAMEBUFFER_HEADER_SIZE`] in an [`FramebufferTypeId::RGB`] tag.
+const RGB_FIELDS_SIZE: usize = 6;
+
+/// Like [`framebuffer_tag`], but verifies every length against the tag's
+/// declared `size` before reading a field or constructing a slice over it,
+/// instead of trusting the tag's contents the way [`Reader`] and
+/// [`slice::from_raw_parts`] otherwise would.
+pub fn checked_framebuffer_tag(tag: &Tag) -> Result<FramebufferTag, FramebufferTagError> {
+    tag_error::check_bounds(tag.size, 0, FRAMEBUFFER_HEADER_SIZE)?;
+
+    let mut reader = Reader::new(tag as *const Tag);
+    reader.skip(8);
+    let address = reader.read_u64();
+    let pitch = reader.read_u32();
+    let width = reader.read_u32();
+    let height = reader.read_u32();
+    let bpp = reader.read_u8();
+    let type_no = reader.read_u8();
+    reader.skip(2);
+    let buffer_type_id = match type_no {
+        0 => Ok(FramebufferTypeId::Indexed),
+        1 => Ok(FramebufferTypeId::RGB),
+        2 => Ok(FramebufferTypeId::Text),
+        id => Err(UnknownFramebufferType(id)),
+    }?;
+    let buffer_type = match buffer_type_id {
+        FramebufferTypeId::Indexed => {
+            tag_error::check_bounds(tag.size, FRAMEBUFFER_HEADER_SIZE, INDEXED_NUM_COLORS_SIZE)?;
+            let num_colors = reader.read_u32();
+            let palette_len = num_colors as usize * core::mem::size_of::<FramebufferColor>();
+            tag_error::check_bounds(
+                tag.size,
+                FRAMEBUFFER_HEADER_SIZE + INDEXED_NUM_COLORS_SIZE,
+                palette_len,
+            )?;
+            let palette = unsafe {
+                slice::from_raw_parts(
+                    reader.current_address() as *const FramebufferColor,
+                    num_colors as usize,
+                )
+            } as &[FramebufferColor];
+            FramebufferType::Indexed { palette }
+        }
+        FramebufferTypeId::RGB => {
+            tag_error::check_bounds(tag.size, FRAMEBUFFER_HEADER_SIZE, RGB_FIELDS_SIZE)?;
+            let red_pos = reader.read_u8();
+            let red_mask = reader.read_u8();
+            let green_pos = reader.read_u8();
+            let green_mask = reader.read_u8();
+            let blue_pos = reader.read_u8();
+            let blue_mask = reader.read_u8();
+            FramebufferType::RGB {
+                red: FramebufferField {
+                    position: red_pos,
+                    size: red_mask,
+                },
+                green: FramebufferField {
+                    position: green_pos,
+                    size: green_mask,
+                },
+                blue: FramebufferField {
+                    position: blue_pos,
+                    size: blue_mask,
+                },
+            }
+        }
+        FramebufferTypeId::Text => FramebufferType::Text,
+    };
+
+    Ok(FramebufferTag {
+        address,
+        pitch,
+        width,
+        height,
+        bpp,
+        buffer_type,
+    })
+}
+
 /// Transforms a [`Tag`] into a [`FramebufferTag`].
 pub fn framebuffer_tag(tag: &Tag) -> Result<FramebufferTag, UnknownFramebufferType> {
     let mut reader = Reader::new(tag as *const Tag);
@@ -163,3 +405,249 @@ pub fn framebuffer_tag(tag: &Tag) -> Result<FramebufferTag, UnknownFramebufferTy
         buffer_type,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests that RGB components are scaled and shifted into their fields.
+    #[test]
+    fn test_pack_color() {
+        let tag = FramebufferTag {
+            address: 0,
+            pitch: 0,
+            width: 0,
+            height: 0,
+            bpp: 32,
+            buffer_type: FramebufferType::RGB {
+                red: FramebufferField {
+                    position: 16,
+                    size: 8,
+                },
+                green: FramebufferField {
+                    position: 8,
+                    size: 8,
+                },
+                blue: FramebufferField {
+                    position: 0,
+                    size: 8,
+                },
+            },
+        };
+
+        assert_eq!(tag.pack_color(0xff, 0x00, 0x80), Some(0x00ff_0080));
+    }
+
+    /// Tests that `pack_color` returns `None` for a non-RGB framebuffer.
+    #[test]
+    fn test_pack_color_wrong_type() {
+        let tag = FramebufferTag {
+            address: 0,
+            pitch: 0,
+            width: 0,
+            height: 0,
+            bpp: 16,
+            buffer_type: FramebufferType::Text,
+        };
+
+        assert_eq!(tag.pack_color(0, 0, 0), None);
+    }
+
+    /// Tests finding the closest palette entry by squared RGB distance.
+    #[test]
+    fn test_nearest_palette_index() {
+        let palette = [
+            FramebufferColor {
+                red: 0,
+                green: 0,
+                blue: 0,
+            },
+            FramebufferColor {
+                red: 255,
+                green: 255,
+                blue: 255,
+            },
+        ];
+        let buffer_type = FramebufferType::Indexed { palette: &palette };
+
+        assert_eq!(buffer_type.nearest_palette_index(10, 10, 10), Some(0));
+        assert_eq!(buffer_type.nearest_palette_index(240, 240, 240), Some(1));
+    }
+
+    /// Tests that out-of-bounds pixel writes are rejected instead of
+    /// writing past the framebuffer.
+    #[test]
+    fn test_put_pixel_out_of_bounds() {
+        let mut backing = [0u8; 16];
+        let tag = FramebufferTag {
+            address: backing.as_mut_ptr() as u64,
+            pitch: 4,
+            width: 4,
+            height: 4,
+            bpp: 32,
+            buffer_type: FramebufferType::RGB {
+                red: FramebufferField {
+                    position: 16,
+                    size: 8,
+                },
+                green: FramebufferField {
+                    position: 8,
+                    size: 8,
+                },
+                blue: FramebufferField {
+                    position: 0,
+                    size: 8,
+                },
+            },
+        };
+
+        assert_eq!(
+            unsafe { tag.put_pixel(4, 0, 0) },
+            Err(PutPixelError::OutOfBounds(PixelOutOfBounds(4, 0)))
+        );
+        assert!(unsafe { tag.put_pixel(1, 1, 0x00ff_0080) }.is_ok());
+    }
+
+    /// Tests that a `bpp` implying a pixel wider than 4 bytes is rejected
+    /// instead of panicking on an out-of-range slice index.
+    #[test]
+    fn test_put_pixel_unsupported_bpp() {
+        let mut backing = [0u8; 16];
+        let tag = FramebufferTag {
+            address: backing.as_mut_ptr() as u64,
+            pitch: 4,
+            width: 4,
+            height: 4,
+            bpp: 255,
+            buffer_type: FramebufferType::RGB {
+                red: FramebufferField {
+                    position: 16,
+                    size: 8,
+                },
+                green: FramebufferField {
+                    position: 8,
+                    size: 8,
+                },
+                blue: FramebufferField {
+                    position: 0,
+                    size: 8,
+                },
+            },
+        };
+
+        assert_eq!(
+            unsafe { tag.put_pixel(0, 0, 0) },
+            Err(PutPixelError::UnsupportedBpp(255))
+        );
+    }
+
+    /// Tests that a field `position` of 32 or more is masked instead of
+    /// overflowing the shift.
+    #[test]
+    fn test_pack_color_position_overflow() {
+        let field = FramebufferField {
+            position: 32,
+            size: 8,
+        };
+
+        assert_eq!(field.pack(0xff), 0xff);
+    }
+
+    /// Returns the bytes of an Indexed framebuffer tag claiming `num_colors`
+    /// palette entries, with `size` reflecting only `actual_colors` of them.
+    fn get_indexed_bytes(num_colors: u32, actual_colors: u32) -> std::vec::Vec<u8> {
+        let header_and_fields = 8 + 8 + 4 + 4 + 4 + 1 + 1 + 2 + 4; // up to and including num_colors
+        let size = header_and_fields as u32
+            + actual_colors * core::mem::size_of::<FramebufferColor>() as u32;
+        let mut bytes = std::vec::Vec::new();
+        bytes.extend(0u32.to_ne_bytes()); // typ, irrelevant to this parser
+        bytes.extend(size.to_ne_bytes());
+        bytes.extend(0u64.to_ne_bytes()); // address
+        bytes.extend(0u32.to_ne_bytes()); // pitch
+        bytes.extend(0u32.to_ne_bytes()); // width
+        bytes.extend(0u32.to_ne_bytes()); // height
+        bytes.push(0); // bpp
+        bytes.push(0); // type_no: Indexed
+        bytes.extend([0, 0]); // skip
+        bytes.extend(num_colors.to_ne_bytes());
+        bytes.resize(
+            bytes.len() + actual_colors as usize * core::mem::size_of::<FramebufferColor>(),
+            0,
+        );
+        bytes
+    }
+
+    /// Tests that a tag too small to even hold the fixed framebuffer
+    /// header is rejected before any field is read, instead of reading
+    /// past the end of the (here, deliberately undersized) backing buffer.
+    #[test]
+    fn test_checked_framebuffer_tag_header_too_small() {
+        let typ = 0u32;
+        let size = 8u32;
+        let bytes = [typ.to_ne_bytes(), size.to_ne_bytes()].concat();
+        let tag = unsafe { &*bytes.as_ptr().cast::<Tag>() };
+
+        assert_eq!(
+            checked_framebuffer_tag(tag),
+            Err(FramebufferTagError::TagError(TagError::OutOfBounds {
+                tag_size: 8,
+                offset: 0,
+                required: FRAMEBUFFER_HEADER_SIZE,
+            }))
+        );
+    }
+
+    /// Tests that an RGB tag too small to hold the mask/position fields is
+    /// rejected instead of read out of bounds.
+    #[test]
+    fn test_checked_framebuffer_tag_rgb_fields_out_of_bounds() {
+        let mut bytes = std::vec::Vec::new();
+        bytes.extend(0u32.to_ne_bytes()); // typ
+        bytes.extend((FRAMEBUFFER_HEADER_SIZE as u32).to_ne_bytes()); // size: header only, no RGB fields
+        bytes.extend(0u64.to_ne_bytes()); // address
+        bytes.extend(0u32.to_ne_bytes()); // pitch
+        bytes.extend(0u32.to_ne_bytes()); // width
+        bytes.extend(0u32.to_ne_bytes()); // height
+        bytes.push(0); // bpp
+        bytes.push(1); // type_no: RGB
+        bytes.extend([0, 0]); // skip
+        let tag = unsafe { &*bytes.as_ptr().cast::<Tag>() };
+
+        assert!(matches!(
+            checked_framebuffer_tag(tag),
+            Err(FramebufferTagError::TagError(TagError::OutOfBounds { .. }))
+        ));
+    }
+
+    /// Tests that a palette claiming more colors than the tag actually has
+    /// room for is rejected instead of read out of bounds.
+    #[test]
+    fn test_checked_framebuffer_tag_palette_out_of_bounds() {
+        let bytes = get_indexed_bytes(10, 2);
+        let tag = unsafe { &*bytes.as_ptr().cast::<Tag>() };
+
+        assert!(matches!(
+            checked_framebuffer_tag(tag),
+            Err(FramebufferTagError::TagError(TagError::OutOfBounds { .. }))
+        ));
+    }
+
+    /// Tests that a well-formed indexed framebuffer tag still parses.
+    #[test]
+    fn test_checked_framebuffer_tag_ok() {
+        let bytes = get_indexed_bytes(2, 2);
+        let tag = unsafe { &*bytes.as_ptr().cast::<Tag>() };
+
+        let fb = checked_framebuffer_tag(tag).expect("tag is well-formed");
+        assert_eq!(
+            fb.buffer_type,
+            FramebufferType::Indexed {
+                palette: &[FramebufferColor {
+                    red: 0,
+                    green: 0,
+                    blue: 0
+                }; 2]
+            }
+        );
+    }
+}
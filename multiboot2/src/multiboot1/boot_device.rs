@@ -0,0 +1,77 @@
+//! Module for [BootDevice].
+
+/// The BIOS boot device, as packed into the Multiboot1 `boot_device`
+/// field: the BIOS drive number followed by up to three nested partition
+/// numbers (DOS-style top level, then up to two BSD-style sub-partitions).
+///
+/// A partition field that is not used is set to `0xFF` by the bootloader;
+/// the corresponding accessor returns `None` in that case.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BootDevice {
+    drive: u8,
+    part1: u8,
+    part2: u8,
+    part3: u8,
+}
+
+impl BootDevice {
+    pub(super) fn from_raw(raw: u32) -> Self {
+        let bytes = raw.to_ne_bytes();
+        Self {
+            drive: bytes[3],
+            part1: bytes[2],
+            part2: bytes[1],
+            part3: bytes[0],
+        }
+    }
+
+    /// The BIOS drive number (e.g. `0x00` for the first floppy, `0x80` for
+    /// the first hard disk).
+    pub fn drive(&self) -> u8 {
+        self.drive
+    }
+
+    /// The top-level DOS partition number, if any.
+    pub fn partition(&self) -> Option<u8> {
+        (self.part1 != 0xFF).then_some(self.part1)
+    }
+
+    /// The BSD sub-partition number within [`Self::partition`], if any.
+    pub fn sub_partition(&self) -> Option<u8> {
+        (self.part2 != 0xFF).then_some(self.part2)
+    }
+
+    /// The BSD sub-sub-partition number within [`Self::sub_partition`], if
+    /// any.
+    pub fn sub_sub_partition(&self) -> Option<u8> {
+        (self.part3 != 0xFF).then_some(self.part3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests that an all-0xFF-partitions boot device (i.e. only a drive,
+    /// no partition) reports no partitions.
+    #[test]
+    fn test_whole_drive() {
+        let raw = u32::from_ne_bytes([0xFF, 0xFF, 0xFF, 0x80]);
+        let device = BootDevice::from_raw(raw);
+        assert_eq!(device.drive(), 0x80);
+        assert_eq!(device.partition(), None);
+        assert_eq!(device.sub_partition(), None);
+        assert_eq!(device.sub_sub_partition(), None);
+    }
+
+    /// Tests a boot device referring to the second partition on the first
+    /// hard disk.
+    #[test]
+    fn test_single_partition() {
+        let raw = u32::from_ne_bytes([0xFF, 0xFF, 0x01, 0x80]);
+        let device = BootDevice::from_raw(raw);
+        assert_eq!(device.drive(), 0x80);
+        assert_eq!(device.partition(), Some(0x01));
+        assert_eq!(device.sub_partition(), None);
+    }
+}
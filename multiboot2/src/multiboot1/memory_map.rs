@@ -0,0 +1,138 @@
+//! Module for [Multiboot1MemoryAreaIter].
+
+use crate::Reader;
+
+/// The type of a [`Multiboot1MemoryArea`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Multiboot1MemoryAreaType {
+    /// Memory available for use by the OS.
+    Available,
+    /// Memory reserved or in an unknown/reserved state.
+    Reserved,
+}
+
+/// A single entry of the Multiboot1 BIOS memory map.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Multiboot1MemoryArea {
+    base_addr: u64,
+    length: u64,
+    typ: u32,
+}
+
+impl Multiboot1MemoryArea {
+    /// The base address of this memory area.
+    pub fn base_addr(&self) -> u64 {
+        self.base_addr
+    }
+
+    /// The length, in bytes, of this memory area.
+    pub fn length(&self) -> u64 {
+        self.length
+    }
+
+    /// The type of this memory area, per the BIOS `int 0x15, eax=0xE820`
+    /// convention: `1` is available, everything else is reserved.
+    pub fn typ(&self) -> Multiboot1MemoryAreaType {
+        match self.typ {
+            1 => Multiboot1MemoryAreaType::Available,
+            _ => Multiboot1MemoryAreaType::Reserved,
+        }
+    }
+}
+
+/// Iterator over the Multiboot1 BIOS memory map.
+///
+/// Each entry is prefixed by its own `size` field, which does not include
+/// the 4 bytes of the `size` field itself.
+#[derive(Debug, Clone)]
+pub struct Multiboot1MemoryAreaIter<'a> {
+    mmap: &'a [u8],
+}
+
+impl<'a> Multiboot1MemoryAreaIter<'a> {
+    pub(super) fn new(mmap: &'a [u8]) -> Self {
+        Self { mmap }
+    }
+}
+
+impl<'a> Iterator for Multiboot1MemoryAreaIter<'a> {
+    type Item = Multiboot1MemoryArea;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.mmap.len() < 4 {
+            return None;
+        }
+
+        let mut reader = Reader::new(self.mmap.as_ptr());
+        let entry_size = reader.read_u32() as usize;
+        let Some(entry_end) = entry_size.checked_add(4) else {
+            return None;
+        };
+        if entry_size < 20 || entry_end > self.mmap.len() {
+            return None;
+        }
+
+        let base_addr = reader.read_u64();
+        let length = reader.read_u64();
+        let typ = reader.read_u32();
+
+        self.mmap = &self.mmap[4 + entry_size..];
+
+        Some(Multiboot1MemoryArea {
+            base_addr,
+            length,
+            typ,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a raw memory map with two entries: one available, one
+    /// reserved.
+    fn get_bytes() -> std::vec::Vec<u8> {
+        let mut bytes = std::vec::Vec::new();
+
+        // Entry 1: available, [0, 0x9_0000).
+        bytes.extend(20u32.to_ne_bytes()); // size, excluding this field
+        bytes.extend(0u64.to_ne_bytes()); // base_addr
+        bytes.extend(0x9_0000u64.to_ne_bytes()); // length
+        bytes.extend(1u32.to_ne_bytes()); // type: available
+
+        // Entry 2: reserved, [0x10_0000, 0x20_0000).
+        bytes.extend(20u32.to_ne_bytes());
+        bytes.extend(0x10_0000u64.to_ne_bytes());
+        bytes.extend(0x10_0000u64.to_ne_bytes());
+        bytes.extend(2u32.to_ne_bytes()); // type: reserved
+
+        bytes
+    }
+
+    /// Tests walking a two-entry memory map.
+    #[test]
+    fn test_iter() {
+        let bytes = get_bytes();
+        let mut iter = Multiboot1MemoryAreaIter::new(&bytes);
+
+        let first = iter.next().expect("must yield the first entry");
+        assert_eq!(first.base_addr(), 0);
+        assert_eq!(first.length(), 0x9_0000);
+        assert_eq!(first.typ(), Multiboot1MemoryAreaType::Available);
+
+        let second = iter.next().expect("must yield the second entry");
+        assert_eq!(second.base_addr(), 0x10_0000);
+        assert_eq!(second.typ(), Multiboot1MemoryAreaType::Reserved);
+
+        assert_eq!(iter.next(), None);
+    }
+
+    /// Tests that a truncated entry does not panic.
+    #[test]
+    fn test_iter_truncated() {
+        let bytes = [20u8, 0, 0, 0]; // claims a 20-byte entry but has none
+        let mut iter = Multiboot1MemoryAreaIter::new(&bytes);
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -0,0 +1,268 @@
+//! Module for the Multiboot1 boot information structure.
+//!
+//! Kernels are frequently booted under the older Multiboot1 protocol
+//! (magic [`MULTIBOOT1_BOOTLOADER_MAGIC`]), whose info block is a
+//! fixed-layout struct with a `flags` bitfield gating which optional
+//! members are present. This module parses that structure and surfaces it
+//! through accessors mirroring the v2 API, so a single crate can serve
+//! kernels regardless of which Multiboot version GRUB used to load them.
+
+mod boot_device;
+mod memory_map;
+
+pub use boot_device::BootDevice;
+pub use memory_map::{Multiboot1MemoryArea, Multiboot1MemoryAreaIter, Multiboot1MemoryAreaType};
+
+use crate::Reader;
+use core::fmt::{Debug, Formatter};
+use core::str;
+
+/// Magic value passed in `eax` by a Multiboot1-compliant bootloader.
+pub const MULTIBOOT1_BOOTLOADER_MAGIC: u32 = 0x2BAD_B002;
+
+const FLAG_MEM: u32 = 1 << 0;
+const FLAG_BOOT_DEVICE: u32 = 1 << 1;
+const FLAG_CMDLINE: u32 = 1 << 2;
+const FLAG_MODS: u32 = 1 << 3;
+const FLAG_MMAP: u32 = 1 << 6;
+const FLAG_BOOT_LOADER_NAME: u32 = 1 << 9;
+
+/// A single loaded module, as described by the Multiboot1 module list.
+///
+/// Mirrors the spec's 16-byte `module_t`: `start`/`end` bound the module in
+/// memory, an optional NUL-terminated string (readable via [`Self::name`])
+/// names the module, and the trailing 4 bytes are reserved and must be 0.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub struct Multiboot1Module {
+    /// Start address of the module in memory.
+    pub start: u32,
+    /// End address of the module in memory.
+    pub end: u32,
+    string: u32,
+    _reserved: u32,
+}
+
+impl Multiboot1Module {
+    /// Reads this module's name/command line as a Rust string slice,
+    /// without the terminating NUL byte.
+    ///
+    /// Returns `None` if the bootloader did not set the string pointer, or
+    /// `Err` if it is not valid UTF-8.
+    ///
+    /// # Safety
+    /// If set, the string pointer must point to a valid NUL-terminated
+    /// string that lives at least as long as `'a`.
+    pub unsafe fn name<'a>(&self) -> Option<Result<&'a str, str::Utf8Error>> {
+        if self.string == 0 {
+            return None;
+        }
+        let cstr = core::ffi::CStr::from_ptr(self.string as usize as *const i8);
+        Some(str::from_utf8(cstr.to_bytes()))
+    }
+}
+
+/// A Multiboot1 boot information structure, as passed to the kernel in
+/// `ebx` when booted with magic [`MULTIBOOT1_BOOTLOADER_MAGIC`] in `eax`.
+///
+/// Unlike the v2 [`crate::BootInformation`], this struct wraps a fixed
+/// byte layout rather than a stream of tags; which fields are valid is
+/// instead gated by the `flags` bitfield.
+pub struct BootInformation<'a> {
+    ptr: *const u8,
+    _marker: core::marker::PhantomData<&'a ()>,
+}
+
+impl<'a> BootInformation<'a> {
+    /// Loads a Multiboot1 boot information structure from the given
+    /// address, as would be found in `ebx` on kernel entry.
+    ///
+    /// # Safety
+    /// `address` must point to a valid Multiboot1 boot information
+    /// structure that lives at least as long as `'a`.
+    pub unsafe fn load(address: usize) -> Self {
+        Self {
+            ptr: address as *const u8,
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    fn flags(&self) -> u32 {
+        Reader::new(self.ptr).read_u32()
+    }
+
+    fn has_flag(&self, flag: u32) -> bool {
+        self.flags() & flag != 0
+    }
+
+    fn reader_at(&self, offset: usize) -> Reader {
+        let mut reader = Reader::new(self.ptr);
+        reader.skip(offset);
+        reader
+    }
+
+    /// Lower memory size in KiB, if the bootloader provided it.
+    pub fn mem_lower(&self) -> Option<u32> {
+        self.has_flag(FLAG_MEM)
+            .then(|| self.reader_at(4).read_u32())
+    }
+
+    /// Upper memory size in KiB, if the bootloader provided it.
+    pub fn mem_upper(&self) -> Option<u32> {
+        self.has_flag(FLAG_MEM)
+            .then(|| self.reader_at(8).read_u32())
+    }
+
+    /// The BIOS boot device, if the bootloader provided it.
+    pub fn boot_device(&self) -> Option<BootDevice> {
+        self.has_flag(FLAG_BOOT_DEVICE)
+            .then(|| BootDevice::from_raw(self.reader_at(12).read_u32()))
+    }
+
+    /// Reads the kernel command line as a Rust string slice, without the
+    /// terminating NUL byte, if the bootloader provided it.
+    ///
+    /// Returns `Err` if the string is not valid UTF-8.
+    pub fn command_line(&self) -> Option<Result<&'a str, str::Utf8Error>> {
+        if !self.has_flag(FLAG_CMDLINE) {
+            return None;
+        }
+
+        let cmdline_addr = self.reader_at(16).read_u32() as usize;
+        let cstr = unsafe { core::ffi::CStr::from_ptr(cmdline_addr as *const i8) };
+        Some(str::from_utf8(cstr.to_bytes()))
+    }
+
+    /// The list of modules loaded alongside the kernel, if the bootloader
+    /// provided it.
+    pub fn modules(&self) -> Option<&'a [Multiboot1Module]> {
+        if !self.has_flag(FLAG_MODS) {
+            return None;
+        }
+
+        let mut reader = self.reader_at(20);
+        let mods_count = reader.read_u32() as usize;
+        let mods_addr = reader.read_u32() as usize;
+
+        Some(unsafe {
+            core::slice::from_raw_parts(mods_addr as *const Multiboot1Module, mods_count)
+        })
+    }
+
+    /// An iterator over the BIOS memory map, if the bootloader provided it.
+    pub fn memory_map(&self) -> Option<Multiboot1MemoryAreaIter<'a>> {
+        if !self.has_flag(FLAG_MMAP) {
+            return None;
+        }
+
+        let mut reader = self.reader_at(44);
+        let mmap_length = reader.read_u32() as usize;
+        let mmap_addr = reader.read_u32() as usize;
+
+        Some(Multiboot1MemoryAreaIter::new(unsafe {
+            core::slice::from_raw_parts(mmap_addr as *const u8, mmap_length)
+        }))
+    }
+
+    /// Reads the bootloader's name as a Rust string slice, without the
+    /// terminating NUL byte, if the bootloader provided it.
+    ///
+    /// Returns `Err` if the string is not valid UTF-8.
+    pub fn boot_loader_name(&self) -> Option<Result<&'a str, str::Utf8Error>> {
+        if !self.has_flag(FLAG_BOOT_LOADER_NAME) {
+            return None;
+        }
+
+        let name_addr = self.reader_at(64).read_u32() as usize;
+        let cstr = unsafe { core::ffi::CStr::from_ptr(name_addr as *const i8) };
+        Some(str::from_utf8(cstr.to_bytes()))
+    }
+}
+
+impl<'a> Debug for BootInformation<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BootInformation")
+            .field("mem_lower", &self.mem_lower())
+            .field("mem_upper", &self.mem_upper())
+            .field("boot_device", &self.boot_device())
+            .field("command_line", &self.command_line())
+            .field("boot_loader_name", &self.boot_loader_name())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds the fixed 88-byte Multiboot1 boot information struct with
+    /// only `mem_lower`/`mem_upper`/`boot_device` present.
+    fn get_bytes(flags: u32) -> std::vec::Vec<u8> {
+        let mut bytes = std::vec::Vec::new();
+        bytes.extend(flags.to_ne_bytes()); // flags
+        bytes.extend(640u32.to_ne_bytes()); // mem_lower
+        bytes.extend(65536u32.to_ne_bytes()); // mem_upper
+        bytes.extend(u32::from_ne_bytes([0xFF, 0xFF, 0xFF, 0x80]).to_ne_bytes()); // boot_device
+        bytes.resize(88, 0);
+        bytes
+    }
+
+    /// Tests that fields gated by an unset flag bit are not surfaced.
+    #[test]
+    fn test_flags_gate_fields() {
+        let bytes = get_bytes(0);
+        let info = unsafe { BootInformation::load(bytes.as_ptr() as usize) };
+        assert_eq!(info.mem_lower(), None);
+        assert_eq!(info.mem_upper(), None);
+        assert_eq!(info.boot_device(), None);
+        assert_eq!(info.command_line(), None);
+    }
+
+    /// Tests that `mem_lower`/`mem_upper`/`boot_device` are read once their
+    /// flag bits are set.
+    #[test]
+    fn test_mem_and_boot_device() {
+        let bytes = get_bytes(FLAG_MEM | FLAG_BOOT_DEVICE);
+        let info = unsafe { BootInformation::load(bytes.as_ptr() as usize) };
+
+        assert_eq!(info.mem_lower(), Some(640));
+        assert_eq!(info.mem_upper(), Some(65536));
+        assert_eq!(info.boot_device().unwrap().drive(), 0x80);
+        assert_eq!(info.boot_device().unwrap().partition(), None);
+    }
+
+    /// Builds a raw two-entry Multiboot1 module list (16 bytes per entry:
+    /// `start`, `end`, `string`, `reserved`).
+    fn get_module_list_bytes() -> std::vec::Vec<u8> {
+        let mut bytes = std::vec::Vec::new();
+        // Module 1.
+        bytes.extend(0x1000u32.to_ne_bytes());
+        bytes.extend(0x2000u32.to_ne_bytes());
+        bytes.extend(0u32.to_ne_bytes());
+        bytes.extend(0u32.to_ne_bytes());
+        // Module 2.
+        bytes.extend(0x3000u32.to_ne_bytes());
+        bytes.extend(0x4000u32.to_ne_bytes());
+        bytes.extend(0u32.to_ne_bytes());
+        bytes.extend(0u32.to_ne_bytes());
+        bytes
+    }
+
+    /// Tests that [`Multiboot1Module`] is sized and strided correctly, so
+    /// that a module list with more than one entry is read from the right
+    /// addresses instead of drifting into the previous entry's trailing
+    /// fields.
+    #[test]
+    fn test_module_list_stride() {
+        assert_eq!(core::mem::size_of::<Multiboot1Module>(), 16);
+
+        let bytes = get_module_list_bytes();
+        let modules =
+            unsafe { core::slice::from_raw_parts(bytes.as_ptr().cast::<Multiboot1Module>(), 2) };
+
+        assert_eq!(modules[0].start, 0x1000);
+        assert_eq!(modules[0].end, 0x2000);
+        assert_eq!(modules[1].start, 0x3000);
+        assert_eq!(modules[1].end, 0x4000);
+    }
+}
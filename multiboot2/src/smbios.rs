@@ -1,3 +1,4 @@
+use crate::tag_error::TagError;
 use crate::{Tag, TagTrait, TagTypeId};
 
 use core::fmt::Debug;
@@ -18,11 +19,146 @@ pub struct SmbiosTag {
     pub tables: [u8],
 }
 
+impl SmbiosTag {
+    /// Returns an iterator over the SMBIOS structures contained in [`Self::tables`].
+    pub fn structures(&self) -> SmbiosStructureIter {
+        SmbiosStructureIter {
+            tables: &self.tables,
+            done: false,
+        }
+    }
+}
+
 impl TagTrait for SmbiosTag {
     fn dst_size(base_tag: &Tag) -> usize {
         assert!(base_tag.size as usize >= METADATA_SIZE);
         base_tag.size as usize - METADATA_SIZE
     }
+
+    fn try_dst_size(base_tag: &Tag) -> Result<usize, TagError> {
+        if (base_tag.size as usize) < METADATA_SIZE {
+            return Err(TagError::TooSmall {
+                size: base_tag.size,
+                minimum: METADATA_SIZE,
+            });
+        }
+        Ok(base_tag.size as usize - METADATA_SIZE)
+    }
+}
+
+/// The fixed-size header every SMBIOS structure begins with.
+const SMBIOS_STRUCT_HEADER_SIZE: usize = 4;
+
+/// Type value of the SMBIOS end-of-table structure.
+const SMBIOS_TYPE_END_OF_TABLE: u8 = 127;
+
+/// A single decoded SMBIOS structure inside [`SmbiosTag::tables`].
+///
+/// Besides `struct_type` and `handle`, this exposes the formatted area as a
+/// raw byte slice and a helper to resolve string-set references.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SmbiosStructure<'a> {
+    /// The SMBIOS structure type, e.g. `0` for BIOS Information or `1` for
+    /// System Information.
+    pub struct_type: u8,
+
+    /// The structure's handle, used to cross-reference structures.
+    pub handle: u16,
+
+    /// The formatted area of the structure, including the 4-byte header.
+    formatted: &'a [u8],
+
+    /// The unformatted string-set that follows the formatted area, not
+    /// including the terminating double-NUL.
+    strings: &'a [u8],
+}
+
+impl<'a> SmbiosStructure<'a> {
+    /// Returns the formatted area of the structure, including the 4-byte
+    /// header (`type`, `length`, `handle`).
+    pub fn formatted_area(&self) -> &'a [u8] {
+        self.formatted
+    }
+
+    /// Resolves a 1-based string reference from the structure's string-set.
+    ///
+    /// Returns `None` if `index` is `0`, out of range, or the referenced
+    /// string is not valid UTF-8.
+    pub fn string(&self, index: u8) -> Option<&'a str> {
+        if index == 0 || self.strings.is_empty() {
+            return None;
+        }
+
+        self.strings
+            .split(|&b| b == 0)
+            .nth(usize::from(index) - 1)
+            .and_then(|s| core::str::from_utf8(s).ok())
+    }
+}
+
+/// Iterator over the DMI/SMBIOS structure stream inside [`SmbiosTag::tables`].
+///
+/// Stops when a type-127 (end-of-table) structure is seen, the blob is
+/// exhausted, or the remaining bytes are too short to contain a well-formed
+/// structure.
+#[derive(Debug)]
+pub struct SmbiosStructureIter<'a> {
+    tables: &'a [u8],
+    done: bool,
+}
+
+impl<'a> Iterator for SmbiosStructureIter<'a> {
+    type Item = SmbiosStructure<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        if self.tables.len() < SMBIOS_STRUCT_HEADER_SIZE {
+            self.done = true;
+            return None;
+        }
+
+        let struct_type = self.tables[0];
+        let length = self.tables[1] as usize;
+        let handle = u16::from_ne_bytes([self.tables[2], self.tables[3]]);
+
+        if length < SMBIOS_STRUCT_HEADER_SIZE || length > self.tables.len() {
+            self.done = true;
+            return None;
+        }
+
+        let formatted = &self.tables[..length];
+        let rest = &self.tables[length..];
+
+        // Find the terminating double-NUL of the string-set.
+        let mut offset = 0;
+        let strings_end = loop {
+            if offset + 1 >= rest.len() {
+                self.done = true;
+                return None;
+            }
+            if rest[offset] == 0 && rest[offset + 1] == 0 {
+                break offset;
+            }
+            offset += 1;
+        };
+
+        let strings = &rest[..strings_end];
+        self.tables = &rest[strings_end + 2..];
+
+        if struct_type == SMBIOS_TYPE_END_OF_TABLE {
+            self.done = true;
+        }
+
+        Some(SmbiosStructure {
+            struct_type,
+            handle,
+            formatted,
+            strings,
+        })
+    }
 }
 
 impl Debug for SmbiosTag {
@@ -66,4 +202,74 @@ mod tests {
         assert_eq!(tag.minor, 0);
         assert_eq!(tag.tables, [0xabu8; 24]);
     }
+
+    /// Builds a raw DMI structure stream with a BIOS Information structure
+    /// (with two strings) followed by an end-of-table structure.
+    fn get_structure_stream() -> std::vec::Vec<u8> {
+        let mut bytes = std::vec::Vec::new();
+        // Structure 1: type 0, length 4, handle 0x0001, no formatted fields
+        // beyond the header, two strings in the string-set.
+        bytes.extend([0u8, 4, 0x01, 0x00]);
+        bytes.extend(b"Vendor\0");
+        bytes.extend(b"1.0\0");
+        bytes.push(0); // terminating double-NUL
+                       // Structure 2: end-of-table, no strings.
+        bytes.extend([127u8, 4, 0x02, 0x00]);
+        bytes.extend([0, 0]);
+        bytes
+    }
+
+    /// Tests walking the DMI structure stream via [`SmbiosStructureIter`].
+    #[test]
+    fn test_structures_iter() {
+        let tables = get_structure_stream();
+        let mut iter = super::SmbiosStructureIter {
+            tables: &tables,
+            done: false,
+        };
+
+        let first = iter.next().expect("must yield the first structure");
+        assert_eq!(first.struct_type, 0);
+        assert_eq!(first.handle, 1);
+        assert_eq!(first.string(1), Some("Vendor"));
+        assert_eq!(first.string(2), Some("1.0"));
+        assert_eq!(first.string(3), None);
+
+        let second = iter.next().expect("must yield the end-of-table structure");
+        assert_eq!(second.struct_type, 127);
+        assert_eq!(second.handle, 2);
+
+        assert_eq!(iter.next(), None);
+    }
+
+    /// Tests that truncated input does not panic and simply ends iteration.
+    #[test]
+    fn test_structures_iter_truncated() {
+        let tables = [0u8, 10, 0x00, 0x00]; // length 10 but only 4 bytes present
+        let mut iter = super::SmbiosStructureIter {
+            tables: &tables,
+            done: false,
+        };
+        assert_eq!(iter.next(), None);
+    }
+
+    /// Tests that a tag too small to even hold the SMBIOS metadata is
+    /// rejected instead of underflowing.
+    #[test]
+    fn test_try_dst_size_too_small() {
+        use crate::tag_error::TagError;
+        use crate::TagTrait;
+
+        let typ: u32 = TagType::Smbios.into();
+        let bytes = [typ.to_ne_bytes(), 4u32.to_ne_bytes()].concat();
+        let tag = unsafe { &*bytes.as_ptr().cast::<Tag>() };
+
+        assert_eq!(
+            SmbiosTag::try_dst_size(tag),
+            Err(TagError::TooSmall {
+                size: 4,
+                minimum: super::METADATA_SIZE,
+            })
+        );
+    }
 }
@@ -0,0 +1,185 @@
+//! Module for [TagError].
+
+use derive_more::Display;
+
+/// Error describing why a tag's declared size could not be trusted while
+/// deriving a dynamically-sized length (e.g. a DST slice length or a
+/// palette entry count) from it.
+///
+/// Early boot code typically has no unwinder, so bounds violations that
+/// would otherwise `assert!` or read out of bounds should be surfaced here
+/// instead, to be handled however the caller sees fit.
+#[derive(Debug, Copy, Clone, Display, PartialEq, Eq)]
+pub enum TagError {
+    /// The tag's `size` field is smaller than the fixed-size metadata the
+    /// tag type requires.
+    #[display(fmt = "Tag size {} is smaller than the minimum of {}", size, minimum)]
+    TooSmall {
+        /// The tag's declared `size` field.
+        size: u32,
+        /// The minimum size the tag type requires.
+        minimum: usize,
+    },
+
+    /// A length derived from the tag's contents (e.g. a palette entry
+    /// count) would read past the end of the tag.
+    #[display(
+        fmt = "Tag of size {} cannot fit {} bytes at offset {}",
+        tag_size,
+        required,
+        offset
+    )]
+    OutOfBounds {
+        /// The tag's declared `size` field.
+        tag_size: u32,
+        /// The offset, relative to the start of the tag, the read would
+        /// start at.
+        offset: usize,
+        /// The number of bytes the read would need from `offset`.
+        required: usize,
+    },
+
+    /// A tag's `size` field would make it extend past the end of the
+    /// boot information structure that contains it.
+    ///
+    /// [`validate_mbi`] also returns this, with a `tag_size` of 0, when it
+    /// reaches the end of the boot information structure without having
+    /// found an end tag.
+    #[display(
+        fmt = "Tag at offset {} with size {} extends past the MBI's total size of {}",
+        mbi_offset,
+        tag_size,
+        mbi_total_size
+    )]
+    TagExceedsMbi {
+        /// The offset of the tag, relative to the start of the MBI.
+        mbi_offset: usize,
+        /// The tag's declared `size` field.
+        tag_size: u32,
+        /// The MBI's declared total size.
+        mbi_total_size: u32,
+    },
+}
+
+#[cfg(feature = "unstable")]
+impl core::error::Error for TagError {}
+
+/// Returns `Ok(())` if `offset + required` bytes still fit inside a tag of
+/// `tag_size` bytes, or [`TagError::OutOfBounds`] otherwise.
+pub(crate) fn check_bounds(tag_size: u32, offset: usize, required: usize) -> Result<(), TagError> {
+    match offset.checked_add(required) {
+        Some(end) if end <= tag_size as usize => Ok(()),
+        _ => Err(TagError::OutOfBounds {
+            tag_size,
+            offset,
+            required,
+        }),
+    }
+}
+
+/// Walks every tag of a boot information structure, checking that each
+/// tag's `size` keeps it within `mbi_total_size`, without constructing a
+/// single DST reference or slice over the tag contents.
+///
+/// This is meant to run once, up front, so that a corrupt or malicious
+/// Multiboot2 structure is rejected before [`crate::TagTrait::dst_size`] or
+/// any tag-specific parser ever trusts a length out of it.
+///
+/// # Safety
+/// `mbi_ptr` must point to at least `mbi_total_size` readable bytes, 8-byte
+/// aligned, per the Multiboot2 spec.
+pub unsafe fn validate_mbi(mbi_ptr: *const u8, mbi_total_size: u32) -> Result<(), TagError> {
+    use crate::{Tag, TagType};
+
+    // The MBI header itself is 8 bytes: total_size (u32) + reserved (u32).
+    let mut offset = 8usize;
+
+    while offset + 8 <= mbi_total_size as usize {
+        let tag = &*mbi_ptr.add(offset).cast::<Tag>();
+
+        if tag.size < 8 {
+            return Err(TagError::TooSmall {
+                size: tag.size,
+                minimum: 8,
+            });
+        }
+
+        let tag_end = offset
+            .checked_add(tag.size as usize)
+            .filter(|&end| end <= mbi_total_size as usize);
+        let Some(tag_end) = tag_end else {
+            return Err(TagError::TagExceedsMbi {
+                mbi_offset: offset,
+                tag_size: tag.size,
+                mbi_total_size,
+            });
+        };
+
+        if tag.typ == TagType::End {
+            return Ok(());
+        }
+
+        // Tags are 8-byte aligned; the end tag is exempt from trailing
+        // padding since there is nothing left to align to.
+        offset = (tag_end + 7) & !7;
+    }
+
+    Err(TagError::TagExceedsMbi {
+        mbi_offset: offset,
+        tag_size: 0,
+        mbi_total_size,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TagType;
+
+    /// Builds a minimal MBI: an 8-byte header, one cmdline tag, and an end
+    /// tag, each padded to 8-byte alignment.
+    fn get_valid_mbi() -> std::vec::Vec<u8> {
+        let cmdline_size = 4 + 4 + 2; // header + 1-byte string + NUL
+        let end_size = 8u32;
+        let total_size = 8 + ((cmdline_size + 7) & !7) + end_size;
+
+        let mut bytes = std::vec::Vec::new();
+        bytes.extend(total_size.to_ne_bytes());
+        bytes.extend(0u32.to_ne_bytes()); // reserved
+
+        let cmdline_typ: u32 = TagType::Cmdline.into();
+        bytes.extend(cmdline_typ.to_ne_bytes());
+        bytes.extend(cmdline_size.to_ne_bytes());
+        bytes.push(b'a');
+        bytes.push(0);
+        while bytes.len() % 8 != 0 {
+            bytes.push(0);
+        }
+
+        let end_typ: u32 = TagType::End.into();
+        bytes.extend(end_typ.to_ne_bytes());
+        bytes.extend(end_size.to_ne_bytes());
+
+        bytes
+    }
+
+    /// Tests that a well-formed MBI validates successfully.
+    #[test]
+    fn test_validate_mbi_ok() {
+        let bytes = get_valid_mbi();
+        let result = unsafe { validate_mbi(bytes.as_ptr(), bytes.len() as u32) };
+        assert_eq!(result, Ok(()));
+    }
+
+    /// Tests that a tag whose size would overrun the MBI is rejected.
+    #[test]
+    fn test_validate_mbi_tag_exceeds_mbi() {
+        let mut bytes = get_valid_mbi();
+        // Inflate the cmdline tag's declared size well past the MBI.
+        let bogus_size = 0xffff_ffffu32;
+        bytes[12..16].copy_from_slice(&bogus_size.to_ne_bytes());
+
+        let result = unsafe { validate_mbi(bytes.as_ptr(), bytes.len() as u32) };
+        assert!(matches!(result, Err(TagError::TagExceedsMbi { .. })));
+    }
+}